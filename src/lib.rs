@@ -1,8 +1,11 @@
 use pyo3::prelude::*;
-use pyo3::exceptions::{PyRuntimeError, PyValueError};
+use pyo3::exceptions::{PyRuntimeError, PyStopAsyncIteration, PyValueError};
+use pyo3::types::{PyBool, PyBytes, PyDict, PyList, PyTuple};
 use pyo3_async_runtimes::tokio::future_into_py;
 use samod::DocumentId;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
+use tokio::sync::broadcast;
 use tokio::sync::Mutex as AsyncMutex;
 use tokio::task::JoinHandle;
 
@@ -10,16 +13,41 @@ use automerge::{transaction::Transactable, ReadDoc};
 
 type TaskSet = Arc<AsyncMutex<Vec<JoinHandle<()>>>>;
 
+/// Build a Python awaitable from an async block, cloning the captured handles
+/// up front.
+///
+/// Every async method here hands its work to Tokio via `future_into_py`, which
+/// drives the awaited body on the runtime with the GIL released — so a flood of
+/// concurrent `splice`/`set_string` calls does not serialize Python threads,
+/// and a spawned task (such as the background sync loop) can re-acquire the GIL
+/// to resolve a coroutine without deadlocking. `a_sync!` is the single place
+/// that expresses this pattern: it clones the named handles and forwards them
+/// into the future, keeping the behavior consistent across `Repo`, `DocHandle`,
+/// and `Text` (mirroring the helper codemp uses in its own pyo3 glue).
+/// Synchronous callers that must block on the runtime instead wrap that wait in
+/// [`Python::allow_threads`] directly (see [`Repo::new`]).
+macro_rules! a_sync {
+    ($py:expr, [$($name:ident = $src:expr),* $(,)?], $body:block) => {{
+        $(let $name = $src.clone();)*
+        future_into_py($py, async move $body)
+    }};
+}
+
 /// A repository for managing Automerge documents with sync capabilities.
 ///
 /// A Repo is similar to a database - it manages documents, storage, and networking.
 /// Documents are CRDTs (Conflict-Free Replicated Data Types) that automatically
 /// merge concurrent changes from multiple users.
 ///
-/// This repo uses in-memory storage and has no network adapters by default.
+/// By default a repo uses in-memory storage and has no network adapters, so
+/// its documents are lost when the process exits. Pass `storage="filesystem"`
+/// (or use [`Repo.open`]) to back the repo with an on-disk directory so
+/// documents survive restarts and can be reloaded by ID.
 ///
 /// Examples:
-///     >>> repo = Repo()
+///     >>> repo = Repo()                              # ephemeral, in-memory
+///     >>> repo = Repo(storage="filesystem", path="./data")
+///     >>> repo = Repo.open("./data")                 # shorthand for the above
 ///     >>> doc = await repo.create()
 ///     >>> await doc.set_string("title", "My Document")
 #[pyclass]
@@ -29,20 +57,51 @@ struct Repo {
     tasks: TaskSet,
 }
 
-#[pymethods]
 impl Repo {
-    #[new]
-    fn new() -> PyResult<Self> {
+    /// Build a repo on a fresh runtime with the requested storage backend.
+    ///
+    /// `storage` is either `"memory"` (the default) or `"filesystem"`; the
+    /// latter requires `path`, a directory that is created if missing and used
+    /// as the durable root for the document history.
+    fn build(py: Python<'_>, storage: &str, path: Option<String>) -> PyResult<Self> {
         // Create a new tokio runtime in a separate thread
         let runtime = tokio::runtime::Runtime::new()
             .map_err(|e| PyRuntimeError::new_err(format!("Failed to create runtime: {}", e)))?;
 
-        let repo = runtime.block_on(async {
-            samod::Repo::build_tokio()
-                .with_storage(samod::storage::InMemoryStorage::new())
-                .load()
-                .await
-        });
+        // Building the repo blocks this thread on the runtime; drop the GIL so
+        // other Python threads keep running while we wait.
+        let repo = match storage {
+            "memory" => py.allow_threads(|| {
+                runtime.block_on(async {
+                    samod::Repo::build_tokio()
+                        .with_storage(samod::storage::InMemoryStorage::new())
+                        .load()
+                        .await
+                })
+            }),
+            "filesystem" => {
+                let path = path.ok_or_else(|| {
+                    PyValueError::new_err("filesystem storage requires a `path` argument")
+                })?;
+                std::fs::create_dir_all(&path).map_err(|e| {
+                    PyRuntimeError::new_err(format!("Failed to create storage directory: {}", e))
+                })?;
+                py.allow_threads(|| {
+                    runtime.block_on(async {
+                        samod::Repo::build_tokio()
+                            .with_storage(samod::storage::TokioFilesystemStorage::new(path))
+                            .load()
+                            .await
+                    })
+                })
+            }
+            other => {
+                return Err(PyValueError::new_err(format!(
+                    "unknown storage backend {:?} (expected \"memory\" or \"filesystem\")",
+                    other
+                )))
+            }
+        };
 
         Ok(Repo {
             inner: Arc::new(repo),
@@ -50,6 +109,31 @@ impl Repo {
             tasks: Arc::new(AsyncMutex::new(Vec::new())),
         })
     }
+}
+
+#[pymethods]
+impl Repo {
+    #[new]
+    #[pyo3(signature = (storage = "memory".to_string(), path = None))]
+    fn new(py: Python<'_>, storage: String, path: Option<String>) -> PyResult<Self> {
+        Repo::build(py, &storage, path)
+    }
+
+    /// Open (or create) a filesystem-backed repo rooted at `path`.
+    ///
+    /// Equivalent to `Repo(storage="filesystem", path=path)`. Documents created
+    /// or synced by the returned repo are flushed to disk under `path` and can
+    /// be reloaded by ID across runs with [`Repo.find`].
+    ///
+    /// Args:
+    ///     path (str): Directory to use as the durable storage root
+    ///
+    /// Returns:
+    ///     Repo: A repo whose document history persists on disk
+    #[staticmethod]
+    fn open(py: Python<'_>, path: String) -> PyResult<Self> {
+        Repo::build(py, "filesystem", Some(path))
+    }
 
     /// Get this repository's unique peer ID.
     ///
@@ -88,10 +172,7 @@ impl Repo {
         py: Python<'py>,
         url: String,
     ) -> PyResult<Bound<'py, PyAny>> {
-        let repo = self.inner.clone();
-        let tasks = self.tasks.clone();
-
-        future_into_py(py, async move {
+        a_sync!(py, [repo = self.inner, tasks = self.tasks], {
             // Parse the URL
             let url = url.parse::<tokio_tungstenite::tungstenite::http::Uri>()
                 .map_err(|e| PyValueError::new_err(format!("Invalid URL: {}", e)))?;
@@ -122,15 +203,12 @@ impl Repo {
         py: Python<'py>,
         doc_id: String,
     ) -> PyResult<Bound<'py, PyAny>> {
-        let repo = self.inner.clone();
-
         let id_str = doc_id.strip_prefix("automerge:").unwrap_or(&doc_id);
 
         let document_id: samod_core::DocumentId = id_str.parse()
             .map_err(|e| PyValueError::new_err(format!("Invalid document ID: {}", e)))?;
 
-
-        future_into_py(py, async move {
+        a_sync!(py, [repo = self.inner], {
             let result = repo.find(document_id).await;
 
             match result {
@@ -161,9 +239,7 @@ impl Repo {
         &self,
         py: Python<'py>,
     ) -> PyResult<Bound<'py, PyAny>> {
-        let repo = self.inner.clone();
-
-        future_into_py(py, async move {
+        a_sync!(py, [repo = self.inner], {
             let initial_doc = automerge::Automerge::new();
             let result = repo.create(initial_doc).await;
 
@@ -199,10 +275,9 @@ impl Repo {
         py: Python<'py>,
         peer_id: String,
     ) -> PyResult<Bound<'py, PyAny>> {
-        let repo = self.inner.clone();
         let peer_id: samod_core::PeerId = peer_id.into();
 
-        future_into_py(py, async move {
+        a_sync!(py, [repo = self.inner], {
             repo.when_connected(peer_id).await
                 .map_err(|_| PyRuntimeError::new_err("Repository stopped"))?;
             Ok(None::<Py<PyAny>>)
@@ -214,10 +289,7 @@ impl Repo {
         &self,
         py: Python<'py>,
     ) -> PyResult<Bound<'py, PyAny>> {
-        let repo = self.inner.clone();
-        let tasks = self.tasks.clone();
-
-        future_into_py(py, async move {
+        a_sync!(py, [repo = self.inner, tasks = self.tasks], {
             for handle in tasks.lock().await.drain(..) {
                 handle.abort();
             }
@@ -225,6 +297,109 @@ impl Repo {
             Ok(None::<Py<PyAny>>)
         })
     }
+
+    /// Serve as a WebSocket sync hub, accepting incoming connections.
+    ///
+    /// Binds a TCP listener at `addr` and, for each client that completes a
+    /// WebSocket upgrade, spawns a sync session with `ConnDirection::Incoming`
+    /// so this repo acts as the server side of the handshake. The accept loop
+    /// and every per-connection task are tracked alongside outgoing connections
+    /// so that `stop()` tears them all down.
+    ///
+    /// The coroutine resolves once the listener is bound, returning a
+    /// [`ServerHandle`] whose `local_addr` reports the actual bound address —
+    /// useful when `addr` requests an ephemeral port (e.g. `"127.0.0.1:0"`).
+    ///
+    /// Args:
+    ///     addr (str): Socket address to bind (e.g. "127.0.0.1:0" or "0.0.0.0:3030")
+    ///
+    /// Returns:
+    ///     Coroutine[ServerHandle]: Resolves when the listener is ready
+    ///
+    /// Raises:
+    ///     RuntimeError: If binding the listener fails
+    fn serve_websocket<'py>(
+        &self,
+        py: Python<'py>,
+        addr: String,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        a_sync!(py, [repo = self.inner, tasks = self.tasks], {
+            let listener = tokio::net::TcpListener::bind(&addr)
+                .await
+                .map_err(|e| PyRuntimeError::new_err(format!("Failed to bind {}: {}", addr, e)))?;
+
+            let local_addr = listener
+                .local_addr()
+                .map_err(|e| PyRuntimeError::new_err(format!("Failed to read bound address: {}", e)))?
+                .to_string();
+
+            // Accept connections in the background, upgrading each to a
+            // WebSocket and handing it to the repo as an incoming sync session.
+            let loop_tasks = tasks.clone();
+            let accept_handle = tokio::spawn(async move {
+                loop {
+                    let (stream, _peer) = match listener.accept().await {
+                        Ok(conn) => conn,
+                        Err(e) => {
+                            tracing::warn!("Accept failed, stopping listener: {:?}", e);
+                            break;
+                        }
+                    };
+
+                    let ws_stream = match tokio_tungstenite::accept_async(stream).await {
+                        Ok(ws) => ws,
+                        Err(e) => {
+                            tracing::warn!("WebSocket handshake failed: {:?}", e);
+                            continue;
+                        }
+                    };
+
+                    let conn_repo = repo.clone();
+                    let handle = tokio::spawn(async move {
+                        let reason = conn_repo
+                            .connect_tungstenite(ws_stream, samod::ConnDirection::Incoming)
+                            .await;
+                        tracing::info!("Incoming connection finished: {:?}", reason);
+                    });
+                    // Drop handles for connections that have already closed so a
+                    // long-lived hub doesn't leak one entry per client.
+                    let mut guard = loop_tasks.lock().await;
+                    guard.retain(|h| !h.is_finished());
+                    guard.push(handle);
+                }
+            });
+
+            tasks.lock().await.push(accept_handle);
+
+            Ok(ServerHandle { local_addr })
+        })
+    }
+}
+
+/// A handle to a running embedded WebSocket sync server.
+///
+/// Returned by [`Repo.serve_websocket`]. The server itself runs in background
+/// tasks owned by the repo; dropping this handle does not stop it (call
+/// `repo.stop()` for that). Its main purpose is to report the bound address.
+#[pyclass]
+struct ServerHandle {
+    local_addr: String,
+}
+
+#[pymethods]
+impl ServerHandle {
+    /// The address the listener is bound to, as `host:port`.
+    ///
+    /// When the server was started with an ephemeral port (port `0`), this
+    /// reports the concrete port the OS assigned.
+    #[getter]
+    fn local_addr(&self) -> &str {
+        &self.local_addr
+    }
+
+    fn __repr__(&self) -> String {
+        format!("ServerHandle(local_addr='{}')", self.local_addr)
+    }
 }
 
 /// A handle to an Automerge document in the repository.
@@ -279,9 +454,7 @@ impl DocHandle {
         &self,
         py: Python<'py>,
     ) -> PyResult<Bound<'py, PyAny>> {
-        let handle = self.inner.clone();
-
-        future_into_py(py, async move {
+        a_sync!(py, [handle = self.inner], {
             let handle = handle.lock().await;
             let bytes = handle.with_document(|doc| {
                 Ok::<_, automerge::AutomergeError>(doc.save())
@@ -294,6 +467,130 @@ impl DocHandle {
         })
     }
 
+    /// Wait for in-flight edits on this handle to settle.
+    ///
+    /// This is purely an ordering barrier, not a save operation: a repo
+    /// persists document changes incrementally as they are applied — samod's
+    /// storage actor writes them to the backend without an explicit save step —
+    /// so there is nothing extra to push to disk. The coroutine acquires the
+    /// document lock, ordering it after any edits already queued on this
+    /// handle, then resolves. Use it as a synchronization point (for example
+    /// before handing a document's ID to another process); it does not itself
+    /// write to storage.
+    ///
+    /// Returns:
+    ///     Coroutine: Resolves once prior edits on this handle have completed
+    fn settle<'py>(
+        &self,
+        py: Python<'py>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        a_sync!(py, [handle = self.inner], {
+            // Acquiring the lock orders this after any in-flight edits; samod
+            // has already persisted them incrementally.
+            let _guard = handle.lock().await;
+            Ok(None::<Py<PyAny>>)
+        })
+    }
+
+    /// Get the document's current heads.
+    ///
+    /// The heads are the hashes of the most recent changes; together they
+    /// identify the document's exact current state. Capture them to name a
+    /// point in history, then pass them back to the `heads=` argument of
+    /// [`get`](Self::get), [`get_string`](Self::get_string) or
+    /// [`get_text`](Self::get_text) to read the document as it was then.
+    ///
+    /// Returns:
+    ///     Coroutine[List[str]]: The current change hashes as hex strings
+    ///
+    /// Raises:
+    ///     RuntimeError: If the operation fails
+    fn heads<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        a_sync!(py, [handle = self.inner], {
+            let handle = handle.lock().await;
+
+            let heads = handle.with_document(|doc| {
+                Ok::<_, automerge::AutomergeError>(
+                    doc.get_heads().iter().map(|h| h.to_string()).collect::<Vec<_>>(),
+                )
+            })
+            .map_err(|e| PyRuntimeError::new_err(format!("Failed to read heads: {}", e)))?;
+
+            Ok(heads)
+        })
+    }
+
+    /// Save the changes made since a known set of heads as a compact delta.
+    ///
+    /// Produces the incremental bytes covering everything added after `heads`,
+    /// so an application can ship just the delta between two known versions
+    /// instead of resaving the whole document. Apply the result on the other
+    /// side with [`load_incremental`](Self::load_incremental).
+    ///
+    /// Args:
+    ///     heads (List[str]): The baseline heads to diff against
+    ///
+    /// Returns:
+    ///     Coroutine[bytes]: The incremental changes after `heads`
+    ///
+    /// Raises:
+    ///     ValueError: If a head is not a valid change hash
+    ///     RuntimeError: If the operation fails
+    fn save_incremental_since<'py>(
+        &self,
+        py: Python<'py>,
+        heads: Vec<String>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let heads = parse_heads(Some(heads))?.unwrap_or_default();
+
+        a_sync!(py, [handle = self.inner], {
+            let handle = handle.lock().await;
+
+            let bytes = handle.with_document(|doc| {
+                let mut out = Vec::new();
+                for change in doc.get_changes(&heads) {
+                    out.extend_from_slice(change.raw_bytes());
+                }
+                Ok::<_, automerge::AutomergeError>(out)
+            })
+            .map_err(|e| PyRuntimeError::new_err(format!("Failed to save delta: {}", e)))?;
+
+            Ok(bytes)
+        })
+    }
+
+    /// Apply an incremental delta produced by another peer or version.
+    ///
+    /// Loads concatenated change bytes (such as those returned by
+    /// [`save_incremental_since`](Self::save_incremental_since)) into this
+    /// document, merging them into the current state.
+    ///
+    /// Args:
+    ///     data (bytes): The incremental changes to apply
+    ///
+    /// Returns:
+    ///     Coroutine: Resolves when the changes have been applied
+    ///
+    /// Raises:
+    ///     RuntimeError: If the bytes are not valid incremental changes
+    fn load_incremental<'py>(
+        &self,
+        py: Python<'py>,
+        data: Vec<u8>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        a_sync!(py, [handle = self.inner], {
+            let handle = handle.lock().await;
+
+            handle.with_document(|doc| {
+                doc.load_incremental(&data)?;
+                Ok::<_, automerge::AutomergeError>(())
+            })
+            .map_err(|e| PyRuntimeError::new_err(format!("Failed to load delta: {}", e)))?;
+
+            Ok(None::<Py<PyAny>>)
+        })
+    }
+
     /// Set a string field in the document root.
     ///
     /// In Automerge, strings are collaborative text sequences by default.
@@ -314,9 +611,7 @@ impl DocHandle {
         key: String,
         value: String,
     ) -> PyResult<Bound<'py, PyAny>> {
-        let handle = self.inner.clone();
-
-        future_into_py(py, async move {
+        a_sync!(py, [handle = self.inner], {
             let handle = handle.lock().await;
 
             handle.with_document(|doc| {
@@ -339,33 +634,40 @@ impl DocHandle {
     ///
     /// Args:
     ///     key (str): The field name to retrieve
+    ///     heads (Optional[List[str]]): If given, read the value as of these
+    ///         heads instead of the current state (see [`heads`](Self::heads))
     ///
     /// Returns:
     ///     Coroutine[Optional[str]]: The string value if it exists, None otherwise
     ///
     /// Raises:
+    ///     ValueError: If a head is not a valid change hash
     ///     RuntimeError: If reading fails
+    #[pyo3(signature = (key, heads = None))]
     fn get_string<'py>(
         &self,
         py: Python<'py>,
         key: String,
+        heads: Option<Vec<String>>,
     ) -> PyResult<Bound<'py, PyAny>> {
-        let handle = self.inner.clone();
+        let heads = parse_heads(heads)?;
 
-        future_into_py(py, async move {
+        a_sync!(py, [handle = self.inner], {
             let handle = handle.lock().await;
 
             let result = handle.with_document(|doc| {
-                match doc.get(automerge::ROOT, &key) {
-                    Ok(Some((automerge::Value::Scalar(s), _))) => {
-                        match s.as_ref() {
-                            automerge::ScalarValue::Str(string) => Ok::<_, automerge::AutomergeError>(Some(string.to_string())),
-                            _ => Ok(None),
+                let got = match &heads {
+                    Some(heads) => doc.get_at(automerge::ROOT, &key, heads)?,
+                    None => doc.get(automerge::ROOT, &key)?,
+                };
+                match got {
+                    Some((automerge::Value::Scalar(s), _)) => match s.as_ref() {
+                        automerge::ScalarValue::Str(string) => {
+                            Ok::<_, automerge::AutomergeError>(Some(string.to_string()))
                         }
-                    }
-                    Ok(Some(_)) => Ok(None),
-                    Ok(None) => Ok(None),
-                    Err(e) => Err(e),
+                        _ => Ok(None),
+                    },
+                    _ => Ok(None),
                 }
             })
             .map_err(|e| PyRuntimeError::new_err(format!("Failed to get field: {}", e)))?;
@@ -387,9 +689,7 @@ impl DocHandle {
         &self,
         py: Python<'py>,
     ) -> PyResult<Bound<'py, PyAny>> {
-        let handle = self.inner.clone();
-
-        future_into_py(py, async move {
+        a_sync!(py, [handle = self.inner], {
             let handle = handle.lock().await;
 
             let keys = handle.with_document(|doc| {
@@ -429,10 +729,7 @@ impl DocHandle {
         key: String,
         value: String,
     ) -> PyResult<Bound<'py, PyAny>> {
-        let handle = self.inner.clone();
-        let document_id = self.document_id.clone();
-
-        future_into_py(py, async move {
+        a_sync!(py, [handle = self.inner, document_id = self.document_id], {
             let handle = handle.lock().await;
 
             let obj_id = handle.with_document(|doc| {
@@ -463,6 +760,8 @@ impl DocHandle {
     ///
     /// Args:
     ///     key (str): The field name
+    ///     heads (Optional[List[str]]): If given, locate the text object as of
+    ///         these heads instead of the current state
     ///
     /// Returns:
     ///     Coroutine[Optional[Text]]: Text handle if the field exists and is a text object
@@ -474,24 +773,28 @@ impl DocHandle {
     ///     >>> text = await doc.get_text("content")
     ///     >>> if text:
     ///     >>>     content = await text.get()
+    #[pyo3(signature = (key, heads = None))]
     fn get_text<'py>(
         &self,
         py: Python<'py>,
         key: String,
+        heads: Option<Vec<String>>,
     ) -> PyResult<Bound<'py, PyAny>> {
-        let handle = self.inner.clone();
-        let document_id = self.document_id.clone();
+        let heads = parse_heads(heads)?;
 
-        future_into_py(py, async move {
+        a_sync!(py, [handle = self.inner, document_id = self.document_id], {
             let handle = handle.lock().await;
 
             let result = handle.with_document(|doc| {
-                match doc.get(automerge::ROOT, &key) {
-                    Ok(Some((automerge::Value::Object(automerge::ObjType::Text), obj_id))) => {
+                let got = match &heads {
+                    Some(heads) => doc.get_at(automerge::ROOT, &key, heads)?,
+                    None => doc.get(automerge::ROOT, &key)?,
+                };
+                match got {
+                    Some((automerge::Value::Object(automerge::ObjType::Text), obj_id)) => {
                         Ok::<_, automerge::AutomergeError>(Some(obj_id))
                     }
-                    Ok(_) => Ok(None),
-                    Err(e) => Err(e),
+                    _ => Ok(None),
                 }
             })
             .map_err(|e| PyRuntimeError::new_err(format!("Failed to get text: {}", e)))?;
@@ -504,6 +807,213 @@ impl DocHandle {
         })
     }
 
+    /// Put a typed value at a root key.
+    ///
+    /// Maps Python values onto Automerge's data model, recursively: `int` →
+    /// integer, `float` → F64, `bool` → boolean, `None` → null, `str` → string,
+    /// `bytes` → byte array, `dict` → Map object, and `list`/`tuple` → List
+    /// object. Nested dicts and lists become nested Automerge objects, so an
+    /// entire JSON-shaped structure can be stored in one call.
+    ///
+    /// Args:
+    ///     key (str): The root field name to set
+    ///     value: A value of one of the supported Python types
+    ///
+    /// Returns:
+    ///     Coroutine: Resolves when the value has been written
+    ///
+    /// Raises:
+    ///     ValueError: If `value` is of an unsupported type
+    ///     RuntimeError: If the operation fails
+    fn put<'py>(
+        &self,
+        py: Python<'py>,
+        key: String,
+        value: Bound<'py, PyAny>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        // Convert eagerly while we hold the GIL; the transaction below runs on
+        // the runtime without it.
+        let val = py_to_val(&value)?;
+
+        a_sync!(py, [handle = self.inner], {
+            let handle = handle.lock().await;
+
+            handle.with_document(|doc| {
+                doc.transact(|tx| {
+                    put_into_map(tx, &automerge::ROOT, &key, &val)?;
+                    Ok::<_, automerge::AutomergeError>(())
+                }).map_err(|e| e.error)?;
+                Ok::<_, automerge::AutomergeError>(())
+            })
+            .map_err(|e| PyRuntimeError::new_err(format!("Document operation failed: {:?}", e)))?;
+
+            Ok(None::<Py<PyAny>>)
+        })
+    }
+
+    /// Get a typed value from a root key.
+    ///
+    /// The inverse of [`put`](Self::put): returns the value converted back into
+    /// the corresponding Python type, recursing into Map and List objects. Text
+    /// objects are returned as plain strings. Returns `None` if the key is
+    /// absent.
+    ///
+    /// Args:
+    ///     key (str): The root field name to read
+    ///     heads (Optional[List[str]]): If given, read the value as of these
+    ///         heads instead of the current state (see [`heads`](Self::heads))
+    ///
+    /// Returns:
+    ///     Coroutine[Optional[object]]: The value, or None if the key is absent
+    ///
+    /// Raises:
+    ///     ValueError: If a head is not a valid change hash
+    ///     RuntimeError: If reading fails
+    #[pyo3(signature = (key, heads = None))]
+    fn get<'py>(
+        &self,
+        py: Python<'py>,
+        key: String,
+        heads: Option<Vec<String>>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let heads = parse_heads(heads)?;
+
+        a_sync!(py, [handle = self.inner], {
+            let handle = handle.lock().await;
+
+            let val = handle.with_document(|doc| {
+                match &heads {
+                    Some(heads) => match doc.get_at(automerge::ROOT, &key, heads)? {
+                        Some((value, id)) => Ok(Some(read_any_at(doc, value, id, heads)?)),
+                        None => Ok(None),
+                    },
+                    None => match doc.get(automerge::ROOT, &key)? {
+                        Some((value, id)) => Ok(Some(read_any(doc, value, id)?)),
+                        None => Ok(None),
+                    },
+                }
+            })
+            .map_err(|e| PyRuntimeError::new_err(format!("Failed to get field: {}", e)))?;
+
+            Python::with_gil(|py| match val {
+                Some(v) => val_to_py(py, &v),
+                None => Ok(py.None()),
+            })
+        })
+    }
+
+    /// Create or replace a counter at a root key.
+    ///
+    /// Counters are a special Automerge scalar whose concurrent increments
+    /// merge additively rather than conflicting, which makes them suitable for
+    /// distributed tallies (likes, votes, reference counts).
+    ///
+    /// Args:
+    ///     key (str): The root field name
+    ///     start (int): The counter's initial value
+    ///
+    /// Returns:
+    ///     Coroutine[Counter]: A handle for incrementing the counter
+    ///
+    /// Raises:
+    ///     RuntimeError: If the operation fails
+    #[pyo3(signature = (key, start = 0))]
+    fn put_counter<'py>(
+        &self,
+        py: Python<'py>,
+        key: String,
+        start: i64,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        a_sync!(py, [handle = self.inner, document_id = self.document_id], {
+            {
+                let handle = handle.lock().await;
+                handle.with_document(|doc| {
+                    doc.transact(|tx| {
+                        tx.put(automerge::ROOT, &key, automerge::ScalarValue::Counter(start.into()))?;
+                        Ok::<_, automerge::AutomergeError>(())
+                    }).map_err(|e| e.error)?;
+                    Ok::<_, automerge::AutomergeError>(())
+                })
+                .map_err(|e| PyRuntimeError::new_err(format!("Failed to create counter: {}", e)))?;
+            }
+
+            Ok(Counter { handle, key, document_id })
+        })
+    }
+
+    /// Broadcast an ephemeral message to peers syncing this document.
+    ///
+    /// Ephemeral messages travel over the same sync connections as document
+    /// changes but are never written into the Automerge history, so they do not
+    /// appear in `dump()`/`doc.save()` output. This is the transport for
+    /// transient collaborative state such as cursor position, selection, or
+    /// "user is typing" indicators. The payload is opaque bytes — encode your
+    /// own cursor/selection JSON into it.
+    ///
+    /// Args:
+    ///     data (bytes): The payload to broadcast to other peers
+    ///
+    /// Returns:
+    ///     Coroutine: Resolves once the message has been handed to the sync layer
+    fn broadcast_ephemeral<'py>(
+        &self,
+        py: Python<'py>,
+        data: Vec<u8>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        a_sync!(py, [handle = self.inner], {
+            let handle = handle.lock().await;
+            handle.broadcast(data);
+            Ok(None::<Py<PyAny>>)
+        })
+    }
+
+    /// Receive ephemeral messages broadcast by other peers for this document.
+    ///
+    /// Returns an async iterator yielding `(peer_id, data)` tuples, where
+    /// `peer_id` is the sending peer's ID and `data` is the opaque bytes it
+    /// broadcast. Messages originating from this repo are not echoed back. Pair
+    /// this with [`broadcast_ephemeral`](Self::broadcast_ephemeral) to attribute
+    /// cursors and presence to their authors.
+    ///
+    /// Returns:
+    ///     EphemeralMessages: An async iterator of `(str, bytes)` tuples
+    ///
+    /// Example:
+    ///     >>> async for peer_id, data in doc.ephemeral_messages():
+    ///     >>>     render_cursor(peer_id, json.loads(data))
+    fn ephemeral_messages(&self) -> EphemeralMessages {
+        let (tx, rx) = broadcast::channel(CHANGE_CHANNEL_CAPACITY);
+        EphemeralMessages {
+            handle: self.inner.clone(),
+            tx: Arc::new(tx),
+            rx: Arc::new(AsyncMutex::new(rx)),
+            started: Arc::new(AtomicBool::new(false)),
+        }
+    }
+
+    /// Observe changes to this document.
+    ///
+    /// Returns an async iterator that yields once each time the document is
+    /// mutated, whether by a local edit or by changes arriving over an active
+    /// sync connection. This lets a collaborative UI react to remote edits
+    /// without polling `get()` in a loop.
+    ///
+    /// Returns:
+    ///     DocChanges: An async iterator of change notifications
+    ///
+    /// Example:
+    ///     >>> async for _ in doc.changes():
+    ///     >>>     await refresh_view()
+    fn changes(&self) -> DocChanges {
+        let (tx, rx) = broadcast::channel(CHANGE_CHANNEL_CAPACITY);
+        DocChanges {
+            handle: self.inner.clone(),
+            tx: Arc::new(tx),
+            rx: Arc::new(AsyncMutex::new(rx)),
+            started: Arc::new(AtomicBool::new(false)),
+        }
+    }
+
 }
 
 /// A handle to an Automerge Text object for collaborative text editing.
@@ -527,22 +1037,28 @@ struct Text {
 
 #[pymethods]
 impl Text {
-    /// Get the current text content as a string.
+    /// Get the text content as a string.
+    ///
+    /// Args:
+    ///     heads (Optional[List[str]]): If given, read the contents as of these
+    ///         heads instead of the current state (see [`DocHandle.heads`])
     ///
     /// Returns:
     ///     Coroutine[str]: The complete text content
     ///
     /// Raises:
+    ///     ValueError: If a head is not a valid change hash
     ///     RuntimeError: If reading fails
-    fn get<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
-        let handle = self.handle.clone();
-        let obj_id = self.obj_id.clone();
+    #[pyo3(signature = (heads = None))]
+    fn get<'py>(&self, py: Python<'py>, heads: Option<Vec<String>>) -> PyResult<Bound<'py, PyAny>> {
+        let heads = parse_heads(heads)?;
 
-        future_into_py(py, async move {
+        a_sync!(py, [handle = self.handle, obj_id = self.obj_id], {
             let handle = handle.lock().await;
 
-            let text = handle.with_document(|doc| {
-                doc.text(&*obj_id)
+            let text = handle.with_document(|doc| match &heads {
+                Some(heads) => doc.text_at(&*obj_id, heads),
+                None => doc.text(&*obj_id),
             })
             .map_err(|e| PyRuntimeError::new_err(format!("Failed to read text: {}", e)))?;
 
@@ -558,10 +1074,7 @@ impl Text {
     /// Raises:
     ///     RuntimeError: If reading fails
     fn length<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
-        let handle = self.handle.clone();
-        let obj_id = self.obj_id.clone();
-
-        future_into_py(py, async move {
+        a_sync!(py, [handle = self.handle, obj_id = self.obj_id], {
             let handle = handle.lock().await;
 
             let len = handle.with_document(|doc| {
@@ -602,10 +1115,7 @@ impl Text {
         delete: isize,
         insert: String,
     ) -> PyResult<Bound<'py, PyAny>> {
-        let handle = self.handle.clone();
-        let obj_id = self.obj_id.clone();
-
-        future_into_py(py, async move {
+        a_sync!(py, [handle = self.handle, obj_id = self.obj_id], {
             let handle = handle.lock().await;
 
             handle.with_document(|doc| {
@@ -671,10 +1181,7 @@ impl Text {
         py: Python<'py>,
         text: String,
     ) -> PyResult<Bound<'py, PyAny>> {
-        let handle = self.handle.clone();
-        let obj_id = self.obj_id.clone();
-
-        future_into_py(py, async move {
+        a_sync!(py, [handle = self.handle, obj_id = self.obj_id], {
             let handle = handle.lock().await;
 
             handle.with_document(|doc| {
@@ -691,11 +1198,679 @@ impl Text {
         })
     }
 
+    /// Observe changes to this text field as incremental diffs.
+    ///
+    /// Returns an async iterator that yields a [`TextChange`] each time the
+    /// underlying text is mutated by a local edit or incoming sync. Each change
+    /// is the minimal splice between the previous and current contents, so a
+    /// caller receives `start`/`end`/`content` rather than the whole buffer.
+    ///
+    /// Returns:
+    ///     TextChanges: An async iterator of `TextChange` diffs
+    ///
+    /// Example:
+    ///     >>> async for change in text.changes():
+    ///     >>>     print(change.start, change.end, change.content)
+    fn changes(&self) -> TextChanges {
+        let (tx, rx) = broadcast::channel(CHANGE_CHANNEL_CAPACITY);
+        TextChanges {
+            handle: self.handle.clone(),
+            obj_id: self.obj_id.clone(),
+            tx: Arc::new(tx),
+            rx: Arc::new(AsyncMutex::new(rx)),
+            started: Arc::new(AtomicBool::new(false)),
+            previous: Arc::new(AsyncMutex::new(None)),
+        }
+    }
+
     fn __repr__(&self) -> String {
         format!("Text(doc='{}')", self.document_id)
     }
 }
 
+/// Capacity of the broadcast channel backing a change observer.
+///
+/// A slow consumer that falls this far behind sees a lag notice (which the
+/// iterator skips) rather than blocking the pump feeding it.
+const CHANGE_CHANNEL_CAPACITY: usize = 32;
+
+/// Spawn the background task that forwards a document's change notifications
+/// into `tx`, unless it has already been started for this observer.
+///
+/// The task clones the samod handle so it can await notifications without
+/// holding the observer's mutex, and exits once every receiver has been
+/// dropped.
+fn ensure_change_pump(
+    handle: &Arc<AsyncMutex<samod::DocHandle>>,
+    tx: &Arc<broadcast::Sender<()>>,
+    started: &Arc<AtomicBool>,
+) {
+    if started.swap(true, Ordering::SeqCst) {
+        return;
+    }
+    let handle = handle.clone();
+    let tx = tx.clone();
+    tokio::spawn(async move {
+        let doc = handle.lock().await.clone();
+        loop {
+            doc.changed().await;
+            if tx.send(()).is_err() {
+                break;
+            }
+        }
+    });
+}
+
+/// Spawn the background task forwarding a document's incoming ephemeral
+/// messages into `tx`, unless it has already been started for this observer.
+///
+/// Mirrors [`ensure_change_pump`] but carries each message's sending peer ID
+/// and opaque payload instead of a bare notification.
+fn ensure_ephemeral_pump(
+    handle: &Arc<AsyncMutex<samod::DocHandle>>,
+    tx: &Arc<broadcast::Sender<(String, Vec<u8>)>>,
+    started: &Arc<AtomicBool>,
+) {
+    if started.swap(true, Ordering::SeqCst) {
+        return;
+    }
+    let handle = handle.clone();
+    let tx = tx.clone();
+    tokio::spawn(async move {
+        let doc = handle.lock().await.clone();
+        while let Some(msg) = doc.ephemeral().await {
+            if tx.send((msg.sender.to_string(), msg.data)).is_err() {
+                break;
+            }
+        }
+    });
+}
+
+/// Async iterator over ephemeral messages received from other peers.
+///
+/// Yielded by [`DocHandle.ephemeral_messages`]; each `__anext__` resolves with
+/// a `(peer_id, data)` tuple for the next message broadcast by another peer.
+#[pyclass]
+struct EphemeralMessages {
+    handle: Arc<AsyncMutex<samod::DocHandle>>,
+    tx: Arc<broadcast::Sender<(String, Vec<u8>)>>,
+    rx: Arc<AsyncMutex<broadcast::Receiver<(String, Vec<u8>)>>>,
+    started: Arc<AtomicBool>,
+}
+
+#[pymethods]
+impl EphemeralMessages {
+    fn __aiter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __anext__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        a_sync!(py, [handle = self.handle, tx = self.tx, started = self.started, rx = self.rx], {
+            // Spawn the pump from inside the runtime-driven body; `tokio::spawn`
+            // panics if called from the plain `__anext__` thread.
+            ensure_ephemeral_pump(&handle, &tx, &started);
+            let mut rx = rx.lock().await;
+            loop {
+                match rx.recv().await {
+                    Ok(message) => return Ok(message),
+                    Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                    Err(broadcast::error::RecvError::Closed) => {
+                        return Err(PyStopAsyncIteration::new_err("document closed"))
+                    }
+                }
+            }
+        })
+    }
+}
+
+/// Async iterator over a document's change notifications.
+///
+/// Yielded by [`DocHandle.changes`]; each `__anext__` resolves the next time
+/// the document is mutated.
+#[pyclass]
+struct DocChanges {
+    handle: Arc<AsyncMutex<samod::DocHandle>>,
+    tx: Arc<broadcast::Sender<()>>,
+    rx: Arc<AsyncMutex<broadcast::Receiver<()>>>,
+    started: Arc<AtomicBool>,
+}
+
+#[pymethods]
+impl DocChanges {
+    fn __aiter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __anext__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        a_sync!(py, [handle = self.handle, tx = self.tx, started = self.started, rx = self.rx], {
+            // Spawn the pump from inside the runtime-driven body; `tokio::spawn`
+            // panics if called from the plain `__anext__` thread.
+            ensure_change_pump(&handle, &tx, &started);
+            let mut rx = rx.lock().await;
+            loop {
+                match rx.recv().await {
+                    Ok(()) => return Ok(None::<Py<PyAny>>),
+                    Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                    Err(broadcast::error::RecvError::Closed) => {
+                        return Err(PyStopAsyncIteration::new_err("document closed"))
+                    }
+                }
+            }
+        })
+    }
+}
+
+/// Async iterator over incremental [`TextChange`] diffs for a text field.
+///
+/// Yielded by [`Text.changes`]; each `__anext__` resolves with the minimal
+/// splice between the previously observed contents and the current contents.
+#[pyclass]
+struct TextChanges {
+    handle: Arc<AsyncMutex<samod::DocHandle>>,
+    obj_id: Arc<automerge::ObjId>,
+    tx: Arc<broadcast::Sender<()>>,
+    rx: Arc<AsyncMutex<broadcast::Receiver<()>>>,
+    started: Arc<AtomicBool>,
+    previous: Arc<AsyncMutex<Option<String>>>,
+}
+
+#[pymethods]
+impl TextChanges {
+    fn __aiter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __anext__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        a_sync!(
+            py,
+            [
+                handle = self.handle,
+                obj_id = self.obj_id,
+                tx = self.tx,
+                started = self.started,
+                rx = self.rx,
+                previous = self.previous
+            ],
+            {
+                // Spawn the pump from inside the runtime-driven body;
+                // `tokio::spawn` panics if called from the plain `__anext__`
+                // thread.
+                ensure_change_pump(&handle, &tx, &started);
+                let mut rx = rx.lock().await;
+                let mut previous = previous.lock().await;
+
+                // Establish a baseline on the first `__anext__` call so the first
+                // yielded diff is measured against the text as it was then.
+                if previous.is_none() {
+                    *previous = Some(read_text(&handle, &obj_id).await?);
+                }
+
+                loop {
+                    match rx.recv().await {
+                        Ok(()) => {}
+                        Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                        Err(broadcast::error::RecvError::Closed) => {
+                            return Err(PyStopAsyncIteration::new_err("document closed"))
+                        }
+                    }
+
+                    let current = read_text(&handle, &obj_id).await?;
+                    let prev = previous.as_deref().unwrap_or("");
+                    let change = TextChange::between(prev, &current);
+
+                    // A notification can fire for an unrelated field; only yield
+                    // when this text actually changed.
+                    if change.start == change.end && change.content.is_empty() {
+                        continue;
+                    }
+
+                    *previous = Some(current);
+                    return Ok(change);
+                }
+            }
+        )
+    }
+}
+
+/// Read the current contents of a text object behind a locked handle.
+async fn read_text(
+    handle: &Arc<AsyncMutex<samod::DocHandle>>,
+    obj_id: &automerge::ObjId,
+) -> PyResult<String> {
+    let handle = handle.lock().await;
+    handle
+        .with_document(|doc| doc.text(obj_id))
+        .map_err(|e| PyRuntimeError::new_err(format!("Failed to read text: {}", e)))
+}
+
+/// A minimal splice describing how a [`Text`] field changed between two states.
+///
+/// Positions are character indices (not byte offsets). `start` and `end` index
+/// into the *previous* contents: the range `[start, end)` was replaced by
+/// `content`. A pure insertion has `start == end`; a pure deletion has an empty
+/// `content`.
+#[pyclass]
+#[derive(Clone)]
+struct TextChange {
+    start: usize,
+    end: usize,
+    content: String,
+}
+
+impl TextChange {
+    /// Compute the minimal splice turning `prev` into `curr`.
+    ///
+    /// Trims the common prefix and suffix so the result is the smallest range
+    /// of the previous text that, when replaced by `content`, yields `curr`.
+    fn between(prev: &str, curr: &str) -> TextChange {
+        let p: Vec<char> = prev.chars().collect();
+        let c: Vec<char> = curr.chars().collect();
+
+        let mut start = 0;
+        while start < p.len() && start < c.len() && p[start] == c[start] {
+            start += 1;
+        }
+
+        let mut end_prev = p.len();
+        let mut end_curr = c.len();
+        while end_prev > start && end_curr > start && p[end_prev - 1] == c[end_curr - 1] {
+            end_prev -= 1;
+            end_curr -= 1;
+        }
+
+        TextChange {
+            start,
+            end: end_prev,
+            content: c[start..end_curr].iter().collect(),
+        }
+    }
+}
+
+#[pymethods]
+impl TextChange {
+    #[getter]
+    fn start(&self) -> usize {
+        self.start
+    }
+
+    #[getter]
+    fn end(&self) -> usize {
+        self.end
+    }
+
+    #[getter]
+    fn content(&self) -> &str {
+        &self.content
+    }
+
+    /// Apply this change to `text`, returning the resulting string.
+    ///
+    /// Replaces the character range `[start, end)` of `text` with `content`.
+    /// Useful for reconstructing the new state in tests.
+    ///
+    /// Args:
+    ///     text (str): The previous text state to apply the change to
+    ///
+    /// Returns:
+    ///     str: The text after applying the splice
+    fn apply(&self, text: &str) -> String {
+        let chars: Vec<char> = text.chars().collect();
+        let start = self.start.min(chars.len());
+        let end = self.end.min(chars.len()).max(start);
+
+        let mut out: String = chars[..start].iter().collect();
+        out.push_str(&self.content);
+        out.extend(&chars[end..]);
+        out
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "TextChange(start={}, end={}, content={:?})",
+            self.start, self.end, self.content
+        )
+    }
+}
+
+/// A handle to an Automerge counter stored at a document's root.
+///
+/// Counters merge concurrent increments additively, so two peers that each add
+/// to the same counter while disconnected converge on the sum once they sync.
+#[pyclass]
+struct Counter {
+    handle: Arc<AsyncMutex<samod::DocHandle>>,
+    key: String,
+    document_id: DocumentId,
+}
+
+#[pymethods]
+impl Counter {
+    /// Increment the counter by `delta` (which may be negative).
+    ///
+    /// Uses Automerge's `increment` operation so the change merges additively
+    /// with concurrent increments from other peers.
+    ///
+    /// Args:
+    ///     delta (int): Amount to add to the counter
+    ///
+    /// Returns:
+    ///     Coroutine: Resolves when the increment has been applied
+    ///
+    /// Raises:
+    ///     RuntimeError: If the operation fails
+    #[pyo3(signature = (delta = 1))]
+    fn increment<'py>(&self, py: Python<'py>, delta: i64) -> PyResult<Bound<'py, PyAny>> {
+        a_sync!(py, [handle = self.handle, key = self.key], {
+            let handle = handle.lock().await;
+
+            handle.with_document(|doc| {
+                doc.transact(|tx| {
+                    tx.increment(automerge::ROOT, key.as_str(), delta)?;
+                    Ok::<_, automerge::AutomergeError>(())
+                }).map_err(|e| e.error)?;
+                Ok::<_, automerge::AutomergeError>(())
+            })
+            .map_err(|e| PyRuntimeError::new_err(format!("Increment failed: {}", e)))?;
+
+            Ok(None::<Py<PyAny>>)
+        })
+    }
+
+    /// Read the counter's current value.
+    ///
+    /// Returns:
+    ///     Coroutine[int]: The current value; a plain integer at the key is
+    ///         returned as is, and any other value (or a missing key) reads as 0
+    ///
+    /// Raises:
+    ///     RuntimeError: If reading fails
+    fn value<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        a_sync!(py, [handle = self.handle, key = self.key], {
+            let handle = handle.lock().await;
+
+            let value = handle.with_document(|doc| {
+                match doc.get(automerge::ROOT, &key)? {
+                    Some((automerge::Value::Scalar(s), _)) => match s.as_ref() {
+                        automerge::ScalarValue::Counter(c) => {
+                            Ok::<_, automerge::AutomergeError>(i64::from(c))
+                        }
+                        automerge::ScalarValue::Int(i) => Ok(*i),
+                        _ => Ok(0),
+                    },
+                    _ => Ok(0),
+                }
+            })
+            .map_err(|e| PyRuntimeError::new_err(format!("Failed to read counter: {}", e)))?;
+
+            Ok(value)
+        })
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Counter(doc='{}', key='{}')", self.document_id, self.key)
+    }
+}
+
+/// An intermediate representation of a value crossing the Python/Automerge
+/// boundary.
+///
+/// Python objects can only be touched while the GIL is held, whereas the
+/// Automerge transaction runs on the runtime without it; `Val` is the
+/// GIL-free snapshot carried between the two. It mirrors the subset of the
+/// Automerge data model that [`DocHandle.put`]/[`DocHandle.get`] support.
+enum Val {
+    Null,
+    Bool(bool),
+    Int(i64),
+    UInt(u64),
+    F64(f64),
+    Str(String),
+    Bytes(Vec<u8>),
+    Map(Vec<(String, Val)>),
+    List(Vec<Val>),
+}
+
+/// Snapshot a Python object into a [`Val`], recursing into dicts and lists.
+///
+/// `bool` is checked before `int` because Python booleans are a subclass of
+/// `int`; dicts, lists, tuples and bytes are matched before the scalar
+/// fallbacks.
+fn py_to_val(obj: &Bound<'_, PyAny>) -> PyResult<Val> {
+    if obj.is_none() {
+        return Ok(Val::Null);
+    }
+    if obj.downcast::<PyBool>().is_ok() {
+        return Ok(Val::Bool(obj.extract::<bool>()?));
+    }
+    if let Ok(dict) = obj.downcast::<PyDict>() {
+        let mut entries = Vec::with_capacity(dict.len());
+        for (k, v) in dict.iter() {
+            entries.push((k.extract::<String>()?, py_to_val(&v)?));
+        }
+        return Ok(Val::Map(entries));
+    }
+    if let Ok(list) = obj.downcast::<PyList>() {
+        return Ok(Val::List(list.iter().map(|v| py_to_val(&v)).collect::<PyResult<_>>()?));
+    }
+    if let Ok(tuple) = obj.downcast::<PyTuple>() {
+        return Ok(Val::List(tuple.iter().map(|v| py_to_val(&v)).collect::<PyResult<_>>()?));
+    }
+    if let Ok(bytes) = obj.downcast::<PyBytes>() {
+        return Ok(Val::Bytes(bytes.as_bytes().to_vec()));
+    }
+    if let Ok(s) = obj.extract::<String>() {
+        return Ok(Val::Str(s));
+    }
+    if let Ok(i) = obj.extract::<i64>() {
+        return Ok(Val::Int(i));
+    }
+    if let Ok(u) = obj.extract::<u64>() {
+        return Ok(Val::UInt(u));
+    }
+    if let Ok(f) = obj.extract::<f64>() {
+        return Ok(Val::F64(f));
+    }
+    Err(PyValueError::new_err(
+        "unsupported value type for put() (expected None, bool, int, float, str, bytes, dict, list or tuple)",
+    ))
+}
+
+/// Convert a [`Val`] back into a Python object.
+fn val_to_py(py: Python<'_>, val: &Val) -> PyResult<Py<PyAny>> {
+    Ok(match val {
+        Val::Null => py.None(),
+        Val::Bool(b) => b.into_py(py),
+        Val::Int(i) => i.into_py(py),
+        Val::UInt(u) => u.into_py(py),
+        Val::F64(f) => f.into_py(py),
+        Val::Str(s) => s.into_py(py),
+        Val::Bytes(b) => PyBytes::new(py, b).into_any().unbind(),
+        Val::Map(entries) => {
+            let dict = PyDict::new(py);
+            for (k, v) in entries {
+                dict.set_item(k, val_to_py(py, v)?)?;
+            }
+            dict.into_any().unbind()
+        }
+        Val::List(items) => {
+            let list = PyList::empty(py);
+            for item in items {
+                list.append(val_to_py(py, item)?)?;
+            }
+            list.into_any().unbind()
+        }
+    })
+}
+
+/// Write a [`Val`] into a Map object (or the document root) under `key`.
+fn put_into_map<T: automerge::transaction::Transactable>(
+    tx: &mut T,
+    obj: &automerge::ObjId,
+    key: &str,
+    val: &Val,
+) -> Result<(), automerge::AutomergeError> {
+    match val {
+        Val::Null => { tx.put(obj, key, automerge::ScalarValue::Null)?; }
+        Val::Bool(b) => { tx.put(obj, key, *b)?; }
+        Val::Int(i) => { tx.put(obj, key, *i)?; }
+        Val::UInt(u) => { tx.put(obj, key, automerge::ScalarValue::Uint(*u))?; }
+        Val::F64(f) => { tx.put(obj, key, *f)?; }
+        Val::Str(s) => { tx.put(obj, key, s.as_str())?; }
+        Val::Bytes(b) => { tx.put(obj, key, automerge::ScalarValue::Bytes(b.clone()))?; }
+        Val::Map(entries) => {
+            let child = tx.put_object(obj, key, automerge::ObjType::Map)?;
+            for (k, v) in entries {
+                put_into_map(tx, &child, k, v)?;
+            }
+        }
+        Val::List(items) => {
+            let child = tx.put_object(obj, key, automerge::ObjType::List)?;
+            for (i, v) in items.iter().enumerate() {
+                insert_into_list(tx, &child, i, v)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Insert a [`Val`] into a List object at `index`.
+fn insert_into_list<T: automerge::transaction::Transactable>(
+    tx: &mut T,
+    obj: &automerge::ObjId,
+    index: usize,
+    val: &Val,
+) -> Result<(), automerge::AutomergeError> {
+    match val {
+        Val::Null => { tx.insert(obj, index, automerge::ScalarValue::Null)?; }
+        Val::Bool(b) => { tx.insert(obj, index, *b)?; }
+        Val::Int(i) => { tx.insert(obj, index, *i)?; }
+        Val::UInt(u) => { tx.insert(obj, index, automerge::ScalarValue::Uint(*u))?; }
+        Val::F64(f) => { tx.insert(obj, index, *f)?; }
+        Val::Str(s) => { tx.insert(obj, index, s.as_str())?; }
+        Val::Bytes(b) => { tx.insert(obj, index, automerge::ScalarValue::Bytes(b.clone()))?; }
+        Val::Map(entries) => {
+            let child = tx.insert_object(obj, index, automerge::ObjType::Map)?;
+            for (k, v) in entries {
+                put_into_map(tx, &child, k, v)?;
+            }
+        }
+        Val::List(items) => {
+            let child = tx.insert_object(obj, index, automerge::ObjType::List)?;
+            for (i, v) in items.iter().enumerate() {
+                insert_into_list(tx, &child, i, v)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Read an Automerge value (scalar or object) into a [`Val`], recursing into
+/// Map and List objects and flattening Text objects to strings.
+fn read_any<R: ReadDoc>(
+    doc: &R,
+    value: automerge::Value<'_>,
+    id: automerge::ObjId,
+) -> Result<Val, automerge::AutomergeError> {
+    match value {
+        automerge::Value::Scalar(s) => Ok(scalar_to_val(&s)),
+        automerge::Value::Object(automerge::ObjType::Map)
+        | automerge::Value::Object(automerge::ObjType::Table) => {
+            let mut entries = Vec::new();
+            for key in doc.keys(&id) {
+                if let Some((v, child)) = doc.get(&id, &key)? {
+                    entries.push((key, read_any(doc, v, child)?));
+                }
+            }
+            Ok(Val::Map(entries))
+        }
+        automerge::Value::Object(automerge::ObjType::List) => {
+            let len = doc.length(&id);
+            let mut items = Vec::with_capacity(len);
+            for i in 0..len {
+                if let Some((v, child)) = doc.get(&id, i)? {
+                    items.push(read_any(doc, v, child)?);
+                }
+            }
+            Ok(Val::List(items))
+        }
+        automerge::Value::Object(automerge::ObjType::Text) => Ok(Val::Str(doc.text(&id)?)),
+    }
+}
+
+/// Read an Automerge value as of a past set of heads, recursing into Map and
+/// List objects and flattening Text objects to strings.
+///
+/// The time-travelling sibling of [`read_any`]: it reads every level through
+/// the `*_at` methods so the whole subtree reflects the historical state.
+fn read_any_at<R: ReadDoc>(
+    doc: &R,
+    value: automerge::Value<'_>,
+    id: automerge::ObjId,
+    heads: &[automerge::ChangeHash],
+) -> Result<Val, automerge::AutomergeError> {
+    match value {
+        automerge::Value::Scalar(s) => Ok(scalar_to_val(&s)),
+        automerge::Value::Object(automerge::ObjType::Map)
+        | automerge::Value::Object(automerge::ObjType::Table) => {
+            let mut entries = Vec::new();
+            for key in doc.keys_at(&id, heads) {
+                if let Some((v, child)) = doc.get_at(&id, &key, heads)? {
+                    entries.push((key, read_any_at(doc, v, child, heads)?));
+                }
+            }
+            Ok(Val::Map(entries))
+        }
+        automerge::Value::Object(automerge::ObjType::List) => {
+            let len = doc.length_at(&id, heads);
+            let mut items = Vec::with_capacity(len);
+            for i in 0..len {
+                if let Some((v, child)) = doc.get_at(&id, i, heads)? {
+                    items.push(read_any_at(doc, v, child, heads)?);
+                }
+            }
+            Ok(Val::List(items))
+        }
+        automerge::Value::Object(automerge::ObjType::Text) => {
+            Ok(Val::Str(doc.text_at(&id, heads)?))
+        }
+    }
+}
+
+/// Parse a list of hex change-hash strings into [`ChangeHash`](automerge::ChangeHash)es.
+///
+/// Returns `None` when no heads were supplied, so callers can branch between a
+/// current read and a historical one.
+fn parse_heads(heads: Option<Vec<String>>) -> PyResult<Option<Vec<automerge::ChangeHash>>> {
+    match heads {
+        None => Ok(None),
+        Some(heads) => {
+            let parsed = heads
+                .iter()
+                .map(|h| {
+                    h.parse::<automerge::ChangeHash>()
+                        .map_err(|e| PyValueError::new_err(format!("Invalid change hash {:?}: {}", h, e)))
+                })
+                .collect::<PyResult<Vec<_>>>()?;
+            Ok(Some(parsed))
+        }
+    }
+}
+
+/// Convert an Automerge scalar into a [`Val`].
+fn scalar_to_val(scalar: &automerge::ScalarValue) -> Val {
+    match scalar {
+        automerge::ScalarValue::Null => Val::Null,
+        automerge::ScalarValue::Boolean(b) => Val::Bool(*b),
+        automerge::ScalarValue::Int(i) => Val::Int(*i),
+        automerge::ScalarValue::Uint(u) => Val::UInt(*u),
+        automerge::ScalarValue::F64(f) => Val::F64(*f),
+        automerge::ScalarValue::Str(s) => Val::Str(s.to_string()),
+        automerge::ScalarValue::Bytes(b) => Val::Bytes(b.clone()),
+        automerge::ScalarValue::Counter(c) => Val::Int(i64::from(c)),
+        automerge::ScalarValue::Timestamp(t) => Val::Int(*t),
+        automerge::ScalarValue::Unknown { bytes, .. } => Val::Bytes(bytes.clone()),
+    }
+}
 
 /// Spork
 ///
@@ -713,5 +1888,11 @@ fn spork_module(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<Repo>()?;
     m.add_class::<DocHandle>()?;
     m.add_class::<Text>()?;
+    m.add_class::<Counter>()?;
+    m.add_class::<ServerHandle>()?;
+    m.add_class::<DocChanges>()?;
+    m.add_class::<EphemeralMessages>()?;
+    m.add_class::<TextChanges>()?;
+    m.add_class::<TextChange>()?;
     Ok(())
 }